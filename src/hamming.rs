@@ -0,0 +1,137 @@
+use pyo3::prelude::*;
+
+// GIL release threshold in characters - Hamming is O(n)
+const HAMMING_GIL_RELEASE_THRESHOLD: usize = 128;
+
+/// Count the positions at which two strings differ
+///
+/// Characters are compared position by position; when the strings have different
+/// lengths the trailing characters of the longer string are all counted as
+/// mismatches (padded behavior). Callers that require equal lengths validate
+/// before calling this helper.
+fn _hamming_distance(s1: &str, s2: &str) -> usize {
+    let mut s1_chars = s1.chars();
+    let mut s2_chars = s2.chars();
+    let mut distance = 0;
+
+    loop {
+        match (s1_chars.next(), s2_chars.next()) {
+            (Some(a), Some(b)) => {
+                if a != b {
+                    distance += 1;
+                }
+            }
+            (Some(_), None) | (None, Some(_)) => distance += 1,
+            (None, None) => break,
+        }
+    }
+
+    distance
+}
+
+/// Calculate Hamming similarity normalized by the longer length
+fn _hamming_similarity(s1: &str, s2: &str) -> f64 {
+    let max_len = s1.chars().count().max(s2.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    let distance = _hamming_distance(s1, s2);
+    1.0 - (distance as f64 / max_len as f64)
+}
+
+#[pyfunction]
+#[pyo3(signature = (s1, s2, pad = false))]
+/// Calculate the Hamming distance between two strings
+///
+/// The Hamming distance is the number of positions at which the corresponding
+/// characters differ. It is defined for equal-length strings such as SKUs,
+/// fingerprints or hashes, and is far cheaper than Levenshtein for such inputs.
+///
+/// Args:
+///     s1 (str): First string to compare
+///     s2 (str): Second string to compare
+///     pad (bool): When True, treat a length difference as additional mismatches
+///         instead of raising. When False (strict), strings of different lengths
+///         raise a ValueError. Defaults to False
+///
+/// Returns:
+///     int: The number of differing positions
+pub fn hamming_distance(s1: &str, s2: &str, pad: bool) -> PyResult<usize> {
+    let s1_len = s1.chars().count();
+    let s2_len = s2.chars().count();
+
+    if !pad && s1_len != s2_len {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "strings must have the same length (use pad=True to allow different lengths)",
+        ));
+    }
+
+    if s1_len > HAMMING_GIL_RELEASE_THRESHOLD || s2_len > HAMMING_GIL_RELEASE_THRESHOLD {
+        Python::with_gil(|py| py.allow_threads(|| Ok(_hamming_distance(s1, s2))))
+    } else {
+        Ok(_hamming_distance(s1, s2))
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (s1, s2, pad = false))]
+/// Calculate the Hamming similarity between two strings
+///
+/// The similarity is the inverse of the Hamming distance, normalized by the
+/// longer length to a value between 0.0 (all positions differ) and 1.0
+/// (identical). Two empty strings return 1.0, matching the other metrics.
+///
+/// Args:
+///     s1 (str): First string to compare
+///     s2 (str): Second string to compare
+///     pad (bool): When True, treat a length difference as additional mismatches
+///         instead of raising. When False (strict), strings of different lengths
+///         raise a ValueError. Defaults to False
+///
+/// Returns:
+///     float: The similarity score between the strings (0.0 to 1.0)
+pub fn hamming_similarity(s1: &str, s2: &str, pad: bool) -> PyResult<f64> {
+    let s1_len = s1.chars().count();
+    let s2_len = s2.chars().count();
+
+    if !pad && s1_len != s2_len {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "strings must have the same length (use pad=True to allow different lengths)",
+        ));
+    }
+
+    if s1_len > HAMMING_GIL_RELEASE_THRESHOLD || s2_len > HAMMING_GIL_RELEASE_THRESHOLD {
+        Python::with_gil(|py| py.allow_threads(|| Ok(_hamming_similarity(s1, s2))))
+    } else {
+        Ok(_hamming_similarity(s1, s2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(_hamming_distance("karolin", "kathrin"), 3);
+        assert_eq!(_hamming_distance("1011101", "1001001"), 2);
+        assert_eq!(_hamming_distance("abc", "abc"), 0);
+        assert_eq!(_hamming_distance("", ""), 0);
+
+        // Padded behavior counts the length delta as mismatches
+        assert_eq!(_hamming_distance("abc", "abcd"), 1);
+        assert_eq!(_hamming_distance("abc", "xyzabc"), 6);
+
+        // Unicode handling
+        assert_eq!(_hamming_distance("café", "cafe"), 1);
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(_hamming_similarity("", ""), 1.0);
+        assert_eq!(_hamming_similarity("abc", "abc"), 1.0);
+        assert_eq!(_hamming_similarity("karolin", "kathrin"), 1.0 - 3.0 / 7.0);
+        assert_eq!(_hamming_similarity("abc", "xyz"), 0.0);
+    }
+}
@@ -1,18 +1,22 @@
 use pyo3::prelude::*;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
 use std::cmp::{max, min};
 
 // GIL release threshold in characters - Jaro-Winkler is O(m)
 const JARO_WINKLER_GIL_RELEASE_THRESHOLD: usize = 128;
 
-fn _matching_characters(a: &str, b: &str, max_distance: usize) -> (usize, usize) {
+/// Find matched character pairs within the Jaro matching window
+///
+/// Returns the number of matches and their `(a_index, b_index)` positions; the
+/// positions are needed to count transpositions afterwards.
+fn _find_matches(a: &str, b: &str, max_distance: usize) -> (usize, Vec<(usize, usize)>) {
     let a_chars: Vec<char> = a.chars().collect();
     let b_chars: Vec<char> = b.chars().collect();
     let mut matches = 0;
-    let mut transpositions = 0;
     let mut b_matches = vec![false; b_chars.len()];
     let mut match_indexes = Vec::new();
 
-    // Find matches
     for (i, &a_char) in a_chars.iter().enumerate() {
         let start = if i > max_distance {
             i - max_distance
@@ -31,7 +35,12 @@ fn _matching_characters(a: &str, b: &str, max_distance: usize) -> (usize, usize)
         }
     }
 
-    // Count transpositions (only counting half as they're counted twice)
+    (matches, match_indexes)
+}
+
+/// Count transpositions from the matched positions (counted once, not doubled)
+fn _count_transpositions(match_indexes: &[(usize, usize)]) -> usize {
+    let mut transpositions = 0;
     for i in 0..match_indexes.len() {
         for j in i + 1..match_indexes.len() {
             if match_indexes[i].1 > match_indexes[j].1 {
@@ -39,11 +48,16 @@ fn _matching_characters(a: &str, b: &str, max_distance: usize) -> (usize, usize)
             }
         }
     }
-
-    (matches, transpositions) // No need to double transpositions anymore
+    transpositions
 }
 
-fn _jaro_winkler_similarity(s1: &str, s2: &str, prefix_weight: f64, max_prefix: usize) -> f64 {
+fn _jaro_winkler_similarity(
+    s1: &str,
+    s2: &str,
+    prefix_weight: f64,
+    max_prefix: usize,
+    cutoff: Option<f64>,
+) -> f64 {
     if prefix_weight < 0.0 || prefix_weight > 0.25 {
         panic!("prefix_weight must be between 0.0 and 0.25");
     }
@@ -56,17 +70,34 @@ fn _jaro_winkler_similarity(s1: &str, s2: &str, prefix_weight: f64, max_prefix:
     }
 
     let max_distance = (max(s1.chars().count(), s2.chars().count()) / 2).saturating_sub(1);
-    let (matches, transpositions) = _matching_characters(s1, s2, max_distance);
+    let (matches, match_indexes) = _find_matches(s1, s2, max_distance);
 
     if matches == 0 {
         return 0.0;
     }
 
     let m = matches as f64;
-    let t = transpositions as f64; // Already in correct form from _matching_characters
     let s1_len = s1.chars().count() as f64;
     let s2_len = s2.chars().count() as f64;
 
+    // Early-out: bound the achievable Jaro-Winkler score using only the match
+    // count (assuming zero transpositions, which can only inflate the score) and
+    // the largest possible prefix boost. If even that cannot reach the cutoff,
+    // reject the candidate without the O(m*n) transposition pass.
+    if let Some(min_similarity) = cutoff {
+        let jaro_max = (m / s1_len + m / s2_len + 1.0) / 3.0;
+        // The prefix boost only raises the score while `l * prefix_weight <= 1`;
+        // clamp the term so the bound stays a valid upper bound for any
+        // (unvalidated) max_prefix/prefix_weight combination.
+        let prefix_term = (max_prefix as f64 * prefix_weight).min(1.0);
+        let upper_bound = jaro_max + prefix_term * (1.0 - jaro_max);
+        if upper_bound < min_similarity {
+            return 0.0;
+        }
+    }
+
+    let t = _count_transpositions(&match_indexes) as f64;
+
     // Calculate basic Jaro similarity
     let jaro = (m / s1_len + m / s2_len + (m - t) / m) / 3.0;
 
@@ -83,9 +114,12 @@ fn _jaro_winkler_similarity(s1: &str, s2: &str, prefix_weight: f64, max_prefix:
 }
 
 fn _jaro_winkler_distance(s1: &str, s2: &str, prefix_weight: f64, max_prefix: usize) -> f64 {
-    1.0 - _jaro_winkler_similarity(s1, s2, prefix_weight, max_prefix)
+    1.0 - _jaro_winkler_similarity(s1, s2, prefix_weight, max_prefix, None)
 }
 
+// The match knobs (score range, limit, Winkler prefix params, worker count)
+// are all independent tunables, so they stay as positional args.
+#[allow(clippy::too_many_arguments)]
 fn _jaro_winkler_match(
     pattern: &str,
     strings: Vec<String>,
@@ -94,16 +128,40 @@ fn _jaro_winkler_match(
     limit: usize,
     prefix_weight: f64,
     max_prefix: usize,
-) -> Vec<(String, f64)> {
+    workers: usize,
+) -> PyResult<Vec<(String, f64)>> {
     let (actual_min, actual_max) = if min <= max { (min, max) } else { (max, min) };
-    let mut matches = Vec::with_capacity(strings.len());
 
-    for s in strings {
-        let score = _jaro_winkler_similarity(pattern, &s, prefix_weight, max_prefix);
-        if score >= actual_min && score <= actual_max {
-            matches.push((s, score));
-        }
-    }
+    // Use the match lower bound as the early-out cutoff for each candidate.
+    let cutoff = Some(actual_min);
+    let score_all = || {
+        strings
+            .into_par_iter()
+            .filter_map(|s| {
+                let score = _jaro_winkler_similarity(pattern, &s, prefix_weight, max_prefix, cutoff);
+                if score >= actual_min && score <= actual_max {
+                    Some((s, score))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<(String, f64)>>()
+    };
+
+    // workers == 0 uses rayon's global pool (one thread per core).
+    let mut matches = if workers > 0 {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(workers)
+            .build()
+            .map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                    "failed to build rayon thread pool: {e}"
+                ))
+            })?;
+        pool.install(score_all)
+    } else {
+        score_all()
+    };
 
     matches.sort_unstable_by(|a, b| {
         b.1.partial_cmp(&a.1)
@@ -111,11 +169,11 @@ fn _jaro_winkler_match(
             .then_with(|| a.0.cmp(&b.0))
     });
 
-    matches.into_iter().take(limit).collect()
+    Ok(matches.into_iter().take(limit).collect())
 }
 
 #[pyfunction]
-#[pyo3(signature = (s1, s2, prefix_weight = 0.1, max_prefix = 4))]
+#[pyo3(signature = (s1, s2, prefix_weight = 0.1, max_prefix = 4, cutoff = None))]
 /// Calculate the Jaro-Winkler similarity between two strings
 ///
 /// The Jaro-Winkler similarity is a measure of similarity between two strings.
@@ -127,6 +185,8 @@ fn _jaro_winkler_match(
 ///     s2 (str): Second string to compare
 ///     prefix_weight (float, optional): Weight for the common prefix (0.0 to 0.25). Defaults to 0.1
 ///     max_prefix (int, optional): Maximum prefix length to consider. Defaults to 4
+///     cutoff (Optional[float]): Minimum similarity required; returns 0.0 early if
+///         the score provably cannot reach it, skipping the transposition count
 ///
 /// Returns:
 ///     float: The Jaro-Winkler similarity between the strings
@@ -135,6 +195,7 @@ pub fn jaro_winkler_similarity(
     s2: &str,
     prefix_weight: f64,
     max_prefix: usize,
+    cutoff: Option<f64>,
 ) -> PyResult<f64> {
     if !(0.0..=0.25).contains(&prefix_weight) {
         return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
@@ -142,15 +203,25 @@ pub fn jaro_winkler_similarity(
         ));
     }
 
+    if let Some(c) = cutoff {
+        if !(0.0..=1.0).contains(&c) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "cutoff must be between 0.0 and 1.0",
+            ));
+        }
+    }
+
     let s1_len = s1.chars().count();
     let s2_len = s2.chars().count();
 
     if s1_len > JARO_WINKLER_GIL_RELEASE_THRESHOLD || s2_len > JARO_WINKLER_GIL_RELEASE_THRESHOLD {
         Python::with_gil(|py| {
-            py.allow_threads(|| Ok(_jaro_winkler_similarity(s1, s2, prefix_weight, max_prefix)))
+            py.allow_threads(|| {
+                Ok(_jaro_winkler_similarity(s1, s2, prefix_weight, max_prefix, cutoff))
+            })
         })
     } else {
-        Ok(_jaro_winkler_similarity(s1, s2, prefix_weight, max_prefix))
+        Ok(_jaro_winkler_similarity(s1, s2, prefix_weight, max_prefix, cutoff))
     }
 }
 
@@ -195,7 +266,8 @@ pub fn jaro_winkler_distance(
 }
 
 #[pyfunction]
-#[pyo3(signature = (pattern, strings, min = 0.0, max = 1.0, limit = 5, prefix_weight = 0.1, max_prefix = 4))]
+#[pyo3(signature = (pattern, strings, min = 0.0, max = 1.0, limit = 5, prefix_weight = 0.1, max_prefix = 4, workers = 0))]
+#[allow(clippy::too_many_arguments)] // independent tunables exposed as keyword args to Python
 /// Find the best Jaro-Winkler matches for a pattern in a list of strings
 ///
 /// Args:
@@ -206,6 +278,8 @@ pub fn jaro_winkler_distance(
 ///     limit (int, optional): Maximum number of results to return. Defaults to 5
 ///     prefix_weight (float, optional): Weight for the common prefix (0.0 to 0.25). Defaults to 0.1
 ///     max_prefix (int, optional): Maximum prefix length to consider. Defaults to 4
+///     workers (int, optional): Number of threads to use for scoring. 0 uses the
+///         rayon default (one per core). Defaults to 0
 ///
 /// Returns:
 ///     List[Tuple[str, float]]: List of tuples containing (matched_string, similarity_score),
@@ -218,6 +292,7 @@ pub fn jaro_winkler_match(
     limit: usize,
     prefix_weight: f64,
     max_prefix: usize,
+    workers: usize,
 ) -> PyResult<Vec<(String, f64)>> {
     if !(0.0..=0.25).contains(&prefix_weight) {
         return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
@@ -225,15 +300,21 @@ pub fn jaro_winkler_match(
         ));
     }
 
-    Ok(_jaro_winkler_match(
-        pattern,
-        strings,
-        min,
-        max,
-        limit,
-        prefix_weight,
-        max_prefix,
-    ))
+    // Release the GIL for the whole batch so the rayon workers run in parallel.
+    Python::with_gil(|py| {
+        py.allow_threads(|| {
+            _jaro_winkler_match(
+                pattern,
+                strings,
+                min,
+                max,
+                limit,
+                prefix_weight,
+                max_prefix,
+                workers,
+            )
+        })
+    })
 }
 
 #[cfg(test)]
@@ -245,47 +326,47 @@ mod tests {
     fn test_jaro_winkler() {
         // Standard test cases (existing)
         assert_relative_eq!(
-            _jaro_winkler_similarity("MARTHA", "MARHTA", 0.1, 4),
+            _jaro_winkler_similarity("MARTHA", "MARHTA", 0.1, 4, None),
             0.961,
             epsilon = 0.001
         );
         assert_relative_eq!(
-            _jaro_winkler_similarity("DWAYNE", "DUANE", 0.1, 4),
+            _jaro_winkler_similarity("DWAYNE", "DUANE", 0.1, 4, None),
             0.840,
             epsilon = 0.001
         );
         assert_relative_eq!(
-            _jaro_winkler_similarity("ABCD", "EFGH", 0.1, 4),
+            _jaro_winkler_similarity("ABCD", "EFGH", 0.1, 4, None),
             0.0,
             epsilon = 0.001
         );
 
         // Base cases
         assert_relative_eq!(
-            _jaro_winkler_similarity("kitten", "sitting", 0.1, 4),
+            _jaro_winkler_similarity("kitten", "sitting", 0.1, 4, None),
             0.746,
             epsilon = 0.001
         );
         assert_relative_eq!(
-            _jaro_winkler_similarity("saturday", "sunday", 0.1, 4),
+            _jaro_winkler_similarity("saturday", "sunday", 0.1, 4, None),
             0.7175,
             epsilon = 0.001
         );
-        assert_eq!(_jaro_winkler_similarity("", "", 0.1, 4), 1.0);
-        assert_eq!(_jaro_winkler_similarity("abc", "", 0.1, 4), 0.0);
-        assert_eq!(_jaro_winkler_similarity("", "xyz", 0.1, 4), 0.0);
-        assert_eq!(_jaro_winkler_similarity("abc", "abc", 0.1, 4), 1.0);
+        assert_eq!(_jaro_winkler_similarity("", "", 0.1, 4, None), 1.0);
+        assert_eq!(_jaro_winkler_similarity("abc", "", 0.1, 4, None), 0.0);
+        assert_eq!(_jaro_winkler_similarity("", "xyz", 0.1, 4, None), 0.0);
+        assert_eq!(_jaro_winkler_similarity("abc", "abc", 0.1, 4, None), 1.0);
 
         // Edge cases
-        assert_eq!(_jaro_winkler_similarity("test", "", 0.1, 4), 0.0);
-        assert_eq!(_jaro_winkler_similarity("", "test", 0.1, 4), 0.0);
+        assert_eq!(_jaro_winkler_similarity("test", "", 0.1, 4, None), 0.0);
+        assert_eq!(_jaro_winkler_similarity("", "test", 0.1, 4, None), 0.0);
         assert_relative_eq!(
-            _jaro_winkler_similarity("abc", "acb", 0.1, 4),
+            _jaro_winkler_similarity("abc", "acb", 0.1, 4, None),
             0.5999,
             epsilon = 0.001
         );
         assert_relative_eq!(
-            _jaro_winkler_similarity("abc", "bca", 0.1, 4),
+            _jaro_winkler_similarity("abc", "bca", 0.1, 4, None),
             0.0,
             epsilon = 0.001
         );
@@ -293,33 +374,33 @@ mod tests {
         // Large string test
         let long_a = "a".repeat(1000);
         let long_b = "b".repeat(1000);
-        assert_eq!(_jaro_winkler_similarity(&long_a, &long_b, 0.1, 4), 0.0);
+        assert_eq!(_jaro_winkler_similarity(&long_a, &long_b, 0.1, 4, None), 0.0);
 
         // Unicode handling
         assert_relative_eq!(
-            _jaro_winkler_similarity("café", "cafe", 0.1, 4),
+            _jaro_winkler_similarity("café", "cafe", 0.1, 4, None),
             0.883,
             epsilon = 0.001
         );
         assert_relative_eq!(
-            _jaro_winkler_similarity("こんにちは", "konnichiwa", 0.1, 4),
+            _jaro_winkler_similarity("こんにちは", "konnichiwa", 0.1, 4, None),
             0.000,
             epsilon = 0.001
         );
 
         // Test different prefix weights
         assert_relative_eq!(
-            _jaro_winkler_similarity("MARTHA", "MARHTA", 0.0, 4),
+            _jaro_winkler_similarity("MARTHA", "MARHTA", 0.0, 4, None),
             0.944,
             epsilon = 0.001
         );
         assert_relative_eq!(
-            _jaro_winkler_similarity("MARTHA", "MARHTA", 0.1, 4),
+            _jaro_winkler_similarity("MARTHA", "MARHTA", 0.1, 4, None),
             0.961,
             epsilon = 0.001
         );
         assert_relative_eq!(
-            _jaro_winkler_similarity("MARTHA", "MARHTA", 0.2, 4),
+            _jaro_winkler_similarity("MARTHA", "MARHTA", 0.2, 4, None),
             0.977,
             epsilon = 0.001
         );
@@ -334,7 +415,7 @@ mod tests {
             "appliance".to_string(),
         ];
 
-        let result = _jaro_winkler_match("apple", strings, 0.0, 1.0, 4, 0.1, 4);
+        let result = _jaro_winkler_match("apple", strings, 0.0, 1.0, 4, 0.1, 4, 0).unwrap();
         assert_eq!(result.len(), 4);
         assert_eq!(result[0].0, "apple");
         assert_relative_eq!(result[0].1, 1.0, epsilon = 0.001);
@@ -342,15 +423,35 @@ mod tests {
         assert_relative_eq!(result[1].1, 0.966, epsilon = 0.001);
     }
 
+    #[test]
+    fn test_cutoff_early_out() {
+        // A reachable cutoff yields the exact score
+        assert_relative_eq!(
+            _jaro_winkler_similarity("MARTHA", "MARHTA", 0.1, 4, Some(0.9)),
+            0.961,
+            epsilon = 0.001
+        );
+        // No shared characters returns 0.0 before the cutoff bound is checked
+        assert_eq!(
+            _jaro_winkler_similarity("abc", "xyz", 0.1, 4, Some(0.5)),
+            0.0
+        );
+        // Dissimilar strings that still share characters are rejected cheaply
+        assert_eq!(
+            _jaro_winkler_similarity("kitten", "sitting", 0.1, 4, Some(0.95)),
+            0.0
+        );
+    }
+
     #[test]
     fn test_max_prefix() {
         assert_relative_eq!(
-            _jaro_winkler_similarity("prefix", "prefixx", 0.1, 4),
+            _jaro_winkler_similarity("prefix", "prefixx", 0.1, 4, None),
             0.971,
             epsilon = 0.001
         );
         assert_relative_eq!(
-            _jaro_winkler_similarity("prefix", "prefixx", 0.1, 6),
+            _jaro_winkler_similarity("prefix", "prefixx", 0.1, 6, None),
             0.980,
             epsilon = 0.001
         );
@@ -1,9 +1,84 @@
 use pyo3::prelude::*;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
 use std::cmp::min;
+use std::collections::HashMap;
+
+// Longest pattern that fits in a single machine word for the Myers fast path
+const MYERS_WORD_BITS: usize = 64;
 
 // GIL release threshold in characters - Levenshtein is O(m*n)
 const LEVENSHTEIN_GIL_RELEASE_THRESHOLD: usize = 64;
 
+/// Myers bit-vector Levenshtein distance for a short pattern
+///
+/// Runs in O(|t|) word operations instead of O(|p|*|t|) by packing a whole DP
+/// column into two `u64` bit vectors. `p` must fit in a machine word
+/// (`p_len <= 64`). Cutoff support is preserved: the score only ever moves by
+/// one per column, so once it can no longer drop to the cutoff we bail early.
+fn _myers_distance(p: &str, t: &str, p_len: usize, t_len: usize, cutoff: Option<usize>) -> usize {
+    // An empty pattern has distance t_len; guard before computing top_bit/full,
+    // whose shifts would underflow for p_len == 0.
+    if p_len == 0 {
+        return if let Some(max_dist) = cutoff {
+            min(t_len, max_dist + 1)
+        } else {
+            t_len
+        };
+    }
+
+    // Bit i set where p[i] == c
+    let mut peq: HashMap<char, u64> = HashMap::new();
+    for (i, c) in p.chars().enumerate() {
+        *peq.entry(c).or_insert(0) |= 1u64 << i;
+    }
+
+    let top_bit = 1u64 << (p_len - 1);
+    let full = if p_len == MYERS_WORD_BITS {
+        u64::MAX
+    } else {
+        (1u64 << p_len) - 1
+    };
+
+    let mut vp: u64 = full;
+    let mut vm: u64 = 0;
+    let mut score = p_len;
+
+    for (k, c) in t.chars().enumerate() {
+        let eq = *peq.get(&c).unwrap_or(&0);
+        let xv = eq | vm;
+        let xh = (((eq & vp).wrapping_add(vp)) ^ vp) | eq;
+        let mut ph = vm | !(xh | vp);
+        let mut mh = vp & xh;
+
+        if ph & top_bit != 0 {
+            score += 1;
+        } else if mh & top_bit != 0 {
+            score -= 1;
+        }
+
+        // HP gets a 1 shifted in at the bottom (Hyyrö/Myers); HN gets a 0.
+        ph = (ph << 1) | 1;
+        mh <<= 1;
+        vp = mh | !(xv | ph);
+        vm = ph & xv;
+
+        // Early stopping: the score can drop by at most one per remaining column
+        if let Some(max_dist) = cutoff {
+            let remaining = t_len - (k + 1);
+            if score.saturating_sub(remaining) > max_dist {
+                return max_dist + 1;
+            }
+        }
+    }
+
+    if let Some(max_dist) = cutoff {
+        min(score, max_dist + 1)
+    } else {
+        score
+    }
+}
+
 /// Calculate the actual distance, with optional early stopping
 fn _levenshtein_distance(s1: &str, s2: &str, cutoff: Option<usize>) -> usize {
     let s1_len = s1.chars().count();
@@ -32,6 +107,18 @@ fn _levenshtein_distance(s1: &str, s2: &str, cutoff: Option<usize>) -> usize {
         }
     }
 
+    // Fast path: when the shorter string fits in a machine word, use the
+    // bit-parallel Myers algorithm, which is O(n) word operations instead of
+    // the O(m*n) row DP below.
+    if min(s1_len, s2_len) <= MYERS_WORD_BITS {
+        let (p, p_len, t, t_len) = if s1_len <= s2_len {
+            (s1, s1_len, s2, s2_len)
+        } else {
+            (s2, s2_len, s1, s1_len)
+        };
+        return _myers_distance(p, t, p_len, t_len, cutoff);
+    }
+
     let mut prev_row: Vec<usize> = (0..=s2_len).collect();
     let mut current_row = vec![0; s2_len + 1];
 
@@ -66,8 +153,106 @@ fn _levenshtein_distance(s1: &str, s2: &str, cutoff: Option<usize>) -> usize {
     }
 }
 
-/// Calculate similarity with optional early stopping
-fn _levenshtein_similarity(s1: &str, s2: &str, cutoff: Option<f64>) -> f64 {
+/// Calculate the optimal string alignment (Damerau-Levenshtein) distance
+///
+/// This extends the standard Levenshtein recurrence with a transposition of two
+/// adjacent characters as a single edit. A configurable `substitution_cost` lets
+/// callers make substitutions more expensive (e.g. 2, matching NLTK/vtext) or
+/// forbid them entirely. The cutoff early-stopping logic mirrors
+/// `_levenshtein_distance`.
+fn _damerau_levenshtein_distance(
+    s1: &str,
+    s2: &str,
+    cutoff: Option<usize>,
+    substitution_cost: usize,
+) -> usize {
+    let s1_chars: Vec<char> = s1.chars().collect();
+    let s2_chars: Vec<char> = s2.chars().collect();
+    let s1_len = s1_chars.len();
+    let s2_len = s2_chars.len();
+
+    // Early returns for empty strings - but respect cutoff!
+    if s1_len == 0 {
+        return if let Some(max_dist) = cutoff {
+            min(s2_len, max_dist + 1)
+        } else {
+            s2_len
+        };
+    }
+    if s2_len == 0 {
+        return if let Some(max_dist) = cutoff {
+            min(s1_len, max_dist + 1)
+        } else {
+            s1_len
+        };
+    }
+
+    // Quick check if absolute length difference exceeds cutoff
+    if let Some(max_dist) = cutoff {
+        if s1_len.abs_diff(s2_len) > max_dist {
+            return max_dist + 1; // Return value larger than cutoff
+        }
+    }
+
+    // We need three rows to account for transpositions (i-2, i-1, i).
+    let mut row_before: Vec<usize> = vec![0; s2_len + 1];
+    let mut prev_row: Vec<usize> = (0..=s2_len).collect();
+    let mut current_row = vec![0; s2_len + 1];
+
+    for i in 0..s1_len {
+        current_row[0] = i + 1;
+        let mut min_dist = current_row[0];
+
+        for j in 0..s2_len {
+            let cost = if s1_chars[i] == s2_chars[j] {
+                0
+            } else {
+                substitution_cost
+            };
+            let mut value = min(
+                min(current_row[j] + 1, prev_row[j + 1] + 1),
+                prev_row[j] + cost,
+            );
+
+            // Transposition of two adjacent characters
+            if i > 0
+                && j > 0
+                && s1_chars[i] == s2_chars[j - 1]
+                && s1_chars[i - 1] == s2_chars[j]
+            {
+                value = min(value, row_before[j - 1] + 1);
+            }
+
+            current_row[j + 1] = value;
+            min_dist = min(min_dist, value);
+        }
+
+        // Early stopping check - if entire row exceeds cutoff
+        if let Some(max_dist) = cutoff {
+            if min_dist > max_dist {
+                return max_dist + 1; // Return value larger than cutoff
+            }
+        }
+
+        std::mem::swap(&mut row_before, &mut prev_row);
+        std::mem::swap(&mut prev_row, &mut current_row);
+    }
+
+    // Return minimum of final distance and cutoff + 1 if cutoff exists
+    if let Some(max_dist) = cutoff {
+        min(prev_row[s2_len], max_dist + 1)
+    } else {
+        prev_row[s2_len]
+    }
+}
+
+/// Calculate Damerau-Levenshtein similarity with optional early stopping
+fn _damerau_levenshtein_similarity(
+    s1: &str,
+    s2: &str,
+    cutoff: Option<f64>,
+    substitution_cost: usize,
+) -> f64 {
     let max_len = s1.chars().count().max(s2.chars().count());
     if max_len == 0 {
         return 1.0;
@@ -80,25 +265,28 @@ fn _levenshtein_similarity(s1: &str, s2: &str, cutoff: Option<f64>) -> f64 {
         None
     };
 
-    let distance = _levenshtein_distance(s1, s2, distance_cutoff);
+    let distance = _damerau_levenshtein_distance(s1, s2, distance_cutoff, substitution_cost);
+    // A substitution_cost > 1 lets the distance exceed max_len, so clamp it to
+    // keep the similarity within the documented 0.0-1.0 range.
+    let distance = distance.min(max_len);
     1.0 - (distance as f64 / max_len as f64)
 }
 
-// Calculate the best matches
-fn _levenshtein_match(
+// Calculate the best Damerau-Levenshtein matches
+fn _damerau_levenshtein_match(
     pattern: &str,
     strings: Vec<String>,
     min: f64,
     max: f64,
     limit: usize,
+    substitution_cost: usize,
 ) -> Vec<(String, f64)> {
     let (actual_min, actual_max) = if min <= max { (min, max) } else { (max, min) };
     let mut matches = Vec::with_capacity(strings.len());
 
     for s in strings {
-        // Use min as cutoff - no need to calculate exact distance if we know
-        // it won't meet the minimum similarity requirement
-        let score = _levenshtein_similarity(pattern, &s, Some(actual_min));
+        let score =
+            _damerau_levenshtein_similarity(pattern, &s, Some(actual_min), substitution_cost);
         if score >= actual_min && score <= actual_max {
             matches.push((s, score));
         }
@@ -113,6 +301,75 @@ fn _levenshtein_match(
     matches.into_iter().take(limit).collect()
 }
 
+/// Calculate similarity with optional early stopping
+fn _levenshtein_similarity(s1: &str, s2: &str, cutoff: Option<f64>) -> f64 {
+    let max_len = s1.chars().count().max(s2.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    // Convert similarity cutoff to distance cutoff
+    let distance_cutoff = if let Some(min_similarity) = cutoff {
+        Some((1.0 - min_similarity) * max_len as f64).map(|x| x.ceil() as usize)
+    } else {
+        None
+    };
+
+    let distance = _levenshtein_distance(s1, s2, distance_cutoff);
+    1.0 - (distance as f64 / max_len as f64)
+}
+
+// Calculate the best matches, scoring candidates across a rayon thread pool
+fn _levenshtein_match(
+    pattern: &str,
+    strings: Vec<String>,
+    min: f64,
+    max: f64,
+    limit: usize,
+    workers: usize,
+) -> PyResult<Vec<(String, f64)>> {
+    let (actual_min, actual_max) = if min <= max { (min, max) } else { (max, min) };
+
+    // Use min as cutoff - no need to calculate exact distance if we know it
+    // won't meet the minimum similarity requirement.
+    let score_all = || {
+        strings
+            .into_par_iter()
+            .filter_map(|s| {
+                let score = _levenshtein_similarity(pattern, &s, Some(actual_min));
+                if score >= actual_min && score <= actual_max {
+                    Some((s, score))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<(String, f64)>>()
+    };
+
+    // workers == 0 uses rayon's global pool (one thread per core).
+    let mut matches = if workers > 0 {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(workers)
+            .build()
+            .map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                    "failed to build rayon thread pool: {e}"
+                ))
+            })?;
+        pool.install(score_all)
+    } else {
+        score_all()
+    };
+
+    matches.sort_unstable_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.0.cmp(&b.0))
+    });
+
+    Ok(matches.into_iter().take(limit).collect())
+}
+
 #[pyfunction]
 #[pyo3(signature = (s1, s2, cutoff = None))]
 /// Calculate the Levenshtein edit distance between two strings
@@ -179,20 +436,140 @@ pub fn levenshtein_similarity(s1: &str, s2: &str, cutoff: Option<f64>) -> PyResu
 ///     min (float, optional): Minimum similarity score (0.0 to 1.0). Defaults to 0.0
 ///     max (float, optional): Maximum similarity score (0.0 to 1.0). Defaults to 1.0
 ///     limit (int, optional): Maximum number of results to return. Defaults to 5
+///     workers (int, optional): Number of threads to use for scoring. 0 uses the
+///         rayon default (one per core). Defaults to 0
 ///
 /// Returns:
 ///     List[Tuple[str, float]]: List of tuples containing (matched_string, similarity_score),
 ///     sorted by score descending
 #[pyfunction]
-#[pyo3(signature = (pattern, strings, min = 0.0, max = 1.0, limit = 5))]
+#[pyo3(signature = (pattern, strings, min = 0.0, max = 1.0, limit = 5, workers = 0))]
 pub fn levenshtein_match(
     pattern: &str,
     strings: Vec<String>,
     min: f64,
     max: f64,
     limit: usize,
+    workers: usize,
 ) -> PyResult<Vec<(String, f64)>> {
-    Ok(_levenshtein_match(pattern, strings, min, max, limit))
+    // Release the GIL for the whole batch so the rayon workers run in parallel.
+    Python::with_gil(|py| {
+        py.allow_threads(|| _levenshtein_match(pattern, strings, min, max, limit, workers))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (s1, s2, cutoff = None, substitution_cost = 1))]
+/// Calculate the Damerau-Levenshtein edit distance between two strings
+///
+/// Unlike the plain Levenshtein distance, a transposition of two adjacent
+/// characters counts as a single edit (optimal string alignment), so
+/// "martha"/"marhta" costs 1 instead of 2. The `substitution_cost` controls how
+/// much a substitution costs; set it to 2 to forbid substitutions in favour of an
+/// insertion plus a deletion.
+///
+/// Args:
+///     s1 (str): First string to compare
+///     s2 (str): Second string to compare
+///     cutoff (Optional[int]): Maximum distance to calculate, returns cutoff + 1 if exceeded
+///     substitution_cost (int): Cost of a single substitution. Defaults to 1
+///
+/// Returns:
+///     int: The edit distance between the strings, or cutoff + 1 if specified and exceeded
+pub fn damerau_levenshtein_distance(
+    s1: &str,
+    s2: &str,
+    cutoff: Option<usize>,
+    substitution_cost: usize,
+) -> PyResult<usize> {
+    let s1_len = s1.chars().count();
+    let s2_len = s2.chars().count();
+
+    if s1_len > LEVENSHTEIN_GIL_RELEASE_THRESHOLD || s2_len > LEVENSHTEIN_GIL_RELEASE_THRESHOLD {
+        Python::with_gil(|py| {
+            py.allow_threads(|| {
+                Ok(_damerau_levenshtein_distance(s1, s2, cutoff, substitution_cost))
+            })
+        })
+    } else {
+        Ok(_damerau_levenshtein_distance(s1, s2, cutoff, substitution_cost))
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (s1, s2, cutoff = None, substitution_cost = 1))]
+/// Calculate the Damerau-Levenshtein similarity between two strings
+///
+/// This is the inverse of the Damerau-Levenshtein distance, normalized to a value
+/// between 0.0 (completely different) and 1.0 (identical).
+///
+/// Args:
+///     s1 (str): First string to compare
+///     s2 (str): Second string to compare
+///     cutoff (Optional[float]): Minimum similarity required, stops early if impossible to reach
+///     substitution_cost (int): Cost of a single substitution. Defaults to 1
+///
+/// Returns:
+///     float: The similarity score between the strings (0.0 to 1.0)
+pub fn damerau_levenshtein_similarity(
+    s1: &str,
+    s2: &str,
+    cutoff: Option<f64>,
+    substitution_cost: usize,
+) -> PyResult<f64> {
+    if let Some(c) = cutoff {
+        if !(0.0..=1.0).contains(&c) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "cutoff must be between 0.0 and 1.0",
+            ));
+        }
+    }
+
+    let s1_len = s1.chars().count();
+    let s2_len = s2.chars().count();
+
+    if s1_len > LEVENSHTEIN_GIL_RELEASE_THRESHOLD || s2_len > LEVENSHTEIN_GIL_RELEASE_THRESHOLD {
+        Python::with_gil(|py| {
+            py.allow_threads(|| {
+                Ok(_damerau_levenshtein_similarity(s1, s2, cutoff, substitution_cost))
+            })
+        })
+    } else {
+        Ok(_damerau_levenshtein_similarity(s1, s2, cutoff, substitution_cost))
+    }
+}
+
+/// Find the best Damerau-Levenshtein matches for a pattern in a list of strings
+///
+/// Args:
+///     pattern (str): The string pattern to match against
+///     strings (List[str]): List of strings to search through
+///     min (float, optional): Minimum similarity score (0.0 to 1.0). Defaults to 0.0
+///     max (float, optional): Maximum similarity score (0.0 to 1.0). Defaults to 1.0
+///     limit (int, optional): Maximum number of results to return. Defaults to 5
+///     substitution_cost (int, optional): Cost of a single substitution. Defaults to 1
+///
+/// Returns:
+///     List[Tuple[str, float]]: List of tuples containing (matched_string, similarity_score),
+///     sorted by score descending
+#[pyfunction]
+#[pyo3(signature = (pattern, strings, min = 0.0, max = 1.0, limit = 5, substitution_cost = 1))]
+pub fn damerau_levenshtein_match(
+    pattern: &str,
+    strings: Vec<String>,
+    min: f64,
+    max: f64,
+    limit: usize,
+    substitution_cost: usize,
+) -> PyResult<Vec<(String, f64)>> {
+    Ok(_damerau_levenshtein_match(
+        pattern,
+        strings,
+        min,
+        max,
+        limit,
+        substitution_cost,
+    ))
 }
 
 // Basic tests to ensure the functions work as expected. Extensive tests are in
@@ -228,6 +605,112 @@ mod tests {
         assert_eq!(_levenshtein_distance("こんにちは", "konnichiwa", None), 10);
     }
 
+    #[test]
+    fn test_myers_matches_row_dp() {
+        // The Myers fast path must agree with the classic cases
+        assert_eq!(_myers_distance("martha", "marhta", 6, 6, None), 2);
+        assert_eq!(_myers_distance("kitten", "sitting", 6, 7, None), 3);
+        assert_eq!(_myers_distance("saturday", "sunday", 8, 6, None), 3);
+        assert_eq!(_myers_distance("abc", "abc", 3, 3, None), 0);
+        assert_eq!(_myers_distance("a", "", 1, 0, None), 1);
+
+        // Exactly 64-char pattern stays on the fast path
+        let p = "a".repeat(64);
+        let t = format!("{}b", "a".repeat(64));
+        assert_eq!(_myers_distance(&p, &t, 64, 65, None), 1);
+
+        // Cutoff early-out still returns cutoff + 1 when exceeded
+        assert_eq!(_myers_distance("abc", "xyz", 3, 3, Some(2)), 3);
+    }
+
+    #[test]
+    fn test_myers_fuzz_against_row_dp() {
+        // Independent O(m*n) row-DP reference so we don't compare Myers to itself.
+        fn row_dp(a: &[char], b: &[char]) -> usize {
+            let mut prev: Vec<usize> = (0..=b.len()).collect();
+            for (i, &ca) in a.iter().enumerate() {
+                let mut cur = vec![i + 1; b.len() + 1];
+                for (j, &cb) in b.iter().enumerate() {
+                    let cost = if ca == cb { 0 } else { 1 };
+                    cur[j + 1] = min(min(cur[j] + 1, prev[j + 1] + 1), prev[j] + cost);
+                }
+                prev = cur;
+            }
+            prev[b.len()]
+        }
+
+        // Deterministic LCG so the test is reproducible without an rng dependency.
+        let mut state: u64 = 0x9e3779b97f4a7c15;
+        let mut next = |bound: u64| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) % bound
+        };
+        let alphabet = ['a', 'b', 'c', 'd'];
+
+        // Explicit empty-input cases (the generator also produces zero lengths).
+        assert_eq!(_myers_distance("", "", 0, 0, None), 0);
+        assert_eq!(_myers_distance("", "abc", 0, 3, None), 3);
+        assert_eq!(_myers_distance("", "abc", 0, 3, Some(1)), 2);
+
+        for _ in 0..2000 {
+            let len_a = next(12) as usize;
+            let len_b = next(12) as usize;
+            let a: Vec<char> = (0..len_a).map(|_| alphabet[next(4) as usize]).collect();
+            let b: Vec<char> = (0..len_b).map(|_| alphabet[next(4) as usize]).collect();
+            let sa: String = a.iter().collect();
+            let sb: String = b.iter().collect();
+            assert_eq!(
+                _myers_distance(&sa, &sb, a.len(), b.len(), None),
+                row_dp(&a, &b),
+                "mismatch for {:?} / {:?}",
+                sa,
+                sb
+            );
+        }
+    }
+
+    #[test]
+    fn test_damerau_distance() {
+        // Transpositions cost 1, not 2
+        assert_eq!(_damerau_levenshtein_distance("martha", "marhta", None, 1), 1);
+        assert_eq!(_damerau_levenshtein_distance("abc", "acb", None, 1), 1);
+        assert_eq!(_damerau_levenshtein_distance("abc", "bca", None, 1), 2);
+
+        // Falls back to Levenshtein for the standard cases
+        assert_eq!(_damerau_levenshtein_distance("kitten", "sitting", None, 1), 3);
+        assert_eq!(_damerau_levenshtein_distance("", "", None, 1), 0);
+        assert_eq!(_damerau_levenshtein_distance("abc", "abc", None, 1), 0);
+        assert_eq!(_damerau_levenshtein_distance("abc", "", None, 1), 3);
+
+        // A higher substitution cost prefers insert + delete over a substitute
+        assert_eq!(_damerau_levenshtein_distance("abc", "adc", None, 1), 1);
+        assert_eq!(_damerau_levenshtein_distance("abc", "adc", None, 2), 2);
+
+        // Unicode handling
+        assert_eq!(_damerau_levenshtein_distance("café", "cafe", None, 1), 1);
+    }
+
+    #[test]
+    fn test_damerau_similarity() {
+        assert_eq!(_damerau_levenshtein_similarity("", "", None, 1), 1.0);
+        assert_eq!(_damerau_levenshtein_similarity("abc", "abc", None, 1), 1.0);
+        // Transposition keeps similarity high relative to plain Levenshtein
+        assert_eq!(
+            _damerau_levenshtein_similarity("martha", "marhta", None, 1),
+            1.0 - 1.0 / 6.0
+        );
+        // substitution_cost = 2 (NLTK/vtext behavior) stays within [0.0, 1.0]
+        assert_eq!(_damerau_levenshtein_similarity("abc", "xyz", None, 2), 0.0);
+        assert!(_damerau_levenshtein_similarity("abc", "abd", None, 2) >= 0.0);
+    }
+
+    #[test]
+    fn test_damerau_distance_with_cutoff() {
+        assert_eq!(_damerau_levenshtein_distance("martha", "marhta", Some(0), 1), 1);
+        assert_eq!(_damerau_levenshtein_distance("kitten", "sitting", Some(2), 1), 3);
+        assert_eq!(_damerau_levenshtein_distance("abc", "def", Some(2), 1), 3);
+    }
+
     #[test]
     fn test_distance_with_cutoff() {
         assert_eq!(_levenshtein_distance("kitten", "sitting", Some(2)), 3);
@@ -276,7 +759,7 @@ mod tests {
             "world".to_string(),
         ];
 
-        let matches = _levenshtein_match("kitten", strings, 0.0, 1.0, 2);
+        let matches = _levenshtein_match("kitten", strings, 0.0, 1.0, 2, 0).unwrap();
 
         assert!((matches[0].1 - 1.0).abs() < f64::EPSILON);
         assert_eq!(matches[0].0, "kitten");
@@ -295,7 +778,7 @@ mod tests {
         ];
 
         // Should only include matches above 0.8 similarity
-        let matches = _levenshtein_match("kitten", strings, 0.8, 1.0, 10);
+        let matches = _levenshtein_match("kitten", strings, 0.8, 1.0, 10, 0).unwrap();
         assert_eq!(matches.len(), 1);
         assert_eq!(matches[0].0, "kitten");
     }
@@ -8,8 +8,11 @@
 /// Each algorithm provides distance, similarity, and fuzzy matching capabilities.
 use pyo3::prelude::*;
 
+mod hamming;
 mod jaro_winkler;
 mod levenshtein;
+mod qgram;
+mod ratcliff_obershelp;
 
 #[pymodule]
 fn marhta(_py: Python, m: &PyModule) -> PyResult<()> {
@@ -17,10 +20,26 @@ fn marhta(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(levenshtein::levenshtein_distance, m)?)?;
     m.add_function(wrap_pyfunction!(levenshtein::levenshtein_similarity, m)?)?;
     m.add_function(wrap_pyfunction!(levenshtein::levenshtein_match, m)?)?;
+    // Damerau-Levenshtein functions
+    m.add_function(wrap_pyfunction!(levenshtein::damerau_levenshtein_distance, m)?)?;
+    m.add_function(wrap_pyfunction!(levenshtein::damerau_levenshtein_similarity, m)?)?;
+    m.add_function(wrap_pyfunction!(levenshtein::damerau_levenshtein_match, m)?)?;
+    // Hamming functions
+    m.add_function(wrap_pyfunction!(hamming::hamming_distance, m)?)?;
+    m.add_function(wrap_pyfunction!(hamming::hamming_similarity, m)?)?;
     // Jaro-Winkler functions
     m.add_function(wrap_pyfunction!(jaro_winkler::jaro_winkler_distance, m)?)?;
     m.add_function(wrap_pyfunction!(jaro_winkler::jaro_winkler_similarity, m)?)?;
     m.add_function(wrap_pyfunction!(jaro_winkler::jaro_winkler_match, m)?)?;
+    // q-gram functions
+    m.add_function(wrap_pyfunction!(qgram::qgram_similarity, m)?)?;
+    m.add_function(wrap_pyfunction!(qgram::qgram_match, m)?)?;
+    // Ratcliff-Obershelp functions
+    m.add_function(wrap_pyfunction!(
+        ratcliff_obershelp::ratcliff_obershelp_similarity,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(ratcliff_obershelp::ratcliff_obershelp_match, m)?)?;
 
     Ok(())
 }
@@ -0,0 +1,228 @@
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+// GIL release threshold in characters - q-gram extraction is O(n)
+const QGRAM_GIL_RELEASE_THRESHOLD: usize = 128;
+
+/// Build the multiset of overlapping q-grams of a string as a count map
+///
+/// For `q = 2`, "night" yields {"ni", "ig", "gh", "ht"}. When the string is
+/// shorter than `q` (but non-empty) the whole string is treated as a single gram,
+/// so short inputs still compare meaningfully. Empty strings yield an empty map.
+fn _qgrams(s: &str, q: usize) -> HashMap<String, usize> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+
+    if chars.is_empty() {
+        return counts;
+    }
+
+    if chars.len() < q {
+        *counts.entry(chars.iter().collect()).or_insert(0) += 1;
+        return counts;
+    }
+
+    for window in chars.windows(q) {
+        *counts.entry(window.iter().collect()).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+/// Compute a q-gram similarity between two strings for the requested metric
+///
+/// Returns `None` when the metric name is not recognised so the caller can raise.
+fn _qgram_similarity(s1: &str, s2: &str, q: usize, metric: &str) -> Option<f64> {
+    let a = _qgrams(s1, q);
+    let b = _qgrams(s2, q);
+
+    // Two strings with no grams (both empty) are identical; a single empty side
+    // shares nothing with the other.
+    if a.is_empty() && b.is_empty() {
+        return Some(1.0);
+    }
+    if a.is_empty() || b.is_empty() {
+        return Some(0.0);
+    }
+
+    // Distinct-gram intersection/union for set-based metrics
+    let distinct_intersection = a.keys().filter(|g| b.contains_key(*g)).count();
+    let distinct_union = a.len() + b.len() - distinct_intersection;
+
+    // Count-based totals and multiset intersection for bag metrics
+    let total_a: usize = a.values().sum();
+    let total_b: usize = b.values().sum();
+    let bag_intersection: usize = a
+        .iter()
+        .map(|(g, &ca)| ca.min(*b.get(g).unwrap_or(&0)))
+        .sum();
+
+    let value = match metric {
+        "jaccard" => distinct_intersection as f64 / distinct_union as f64,
+        "dice" | "sorensen" | "sorensen-dice" => {
+            2.0 * bag_intersection as f64 / (total_a + total_b) as f64
+        }
+        "cosine" => {
+            let dot: f64 = a
+                .iter()
+                .map(|(g, &ca)| (ca * b.get(g).copied().unwrap_or(0)) as f64)
+                .sum();
+            let norm_a: f64 = a.values().map(|&c| (c * c) as f64).sum::<f64>().sqrt();
+            let norm_b: f64 = b.values().map(|&c| (c * c) as f64).sum::<f64>().sqrt();
+            dot / (norm_a * norm_b)
+        }
+        "overlap" => distinct_intersection as f64 / a.len().min(b.len()) as f64,
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+fn _qgram_match(
+    pattern: &str,
+    strings: Vec<String>,
+    min: f64,
+    max: f64,
+    limit: usize,
+    q: usize,
+    metric: &str,
+) -> Vec<(String, f64)> {
+    let (actual_min, actual_max) = if min <= max { (min, max) } else { (max, min) };
+    let mut matches = Vec::with_capacity(strings.len());
+
+    for s in strings {
+        // Unknown metrics are rejected by the pyfunction before we get here.
+        let score = _qgram_similarity(pattern, &s, q, metric).unwrap_or(0.0);
+        if score >= actual_min && score <= actual_max {
+            matches.push((s, score));
+        }
+    }
+
+    matches.sort_unstable_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.0.cmp(&b.0))
+    });
+
+    matches.into_iter().take(limit).collect()
+}
+
+fn _validate_args(q: usize, metric: &str) -> PyResult<()> {
+    if q == 0 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "q must be at least 1",
+        ));
+    }
+    if !matches!(
+        metric,
+        "jaccard" | "dice" | "sorensen" | "sorensen-dice" | "cosine" | "overlap"
+    ) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "metric must be one of 'jaccard', 'dice', 'cosine', 'overlap'",
+        ));
+    }
+    Ok(())
+}
+
+#[pyfunction]
+#[pyo3(signature = (s1, s2, q = 2, metric = "jaccard"))]
+/// Calculate a q-gram set/bag similarity between two strings
+///
+/// The strings are decomposed into their overlapping q-grams and compared with
+/// one of several measures. Unlike edit distance, these are robust to
+/// transpositions and partial overlaps on long or reordered strings.
+///
+/// Args:
+///     s1 (str): First string to compare
+///     s2 (str): Second string to compare
+///     q (int): Length of each gram. Defaults to 2
+///     metric (str): One of "jaccard", "dice", "cosine" or "overlap". Defaults to "jaccard"
+///
+/// Returns:
+///     float: The similarity score between the strings (0.0 to 1.0)
+pub fn qgram_similarity(s1: &str, s2: &str, q: usize, metric: &str) -> PyResult<f64> {
+    _validate_args(q, metric)?;
+
+    let s1_len = s1.chars().count();
+    let s2_len = s2.chars().count();
+
+    let score = if s1_len > QGRAM_GIL_RELEASE_THRESHOLD || s2_len > QGRAM_GIL_RELEASE_THRESHOLD {
+        Python::with_gil(|py| py.allow_threads(|| _qgram_similarity(s1, s2, q, metric)))
+    } else {
+        _qgram_similarity(s1, s2, q, metric)
+    };
+
+    // _validate_args already accepted the metric, so this cannot be None.
+    Ok(score.unwrap_or(0.0))
+}
+
+#[pyfunction]
+#[pyo3(signature = (pattern, strings, min = 0.0, max = 1.0, limit = 5, q = 2, metric = "jaccard"))]
+/// Find the best q-gram matches for a pattern in a list of strings
+///
+/// Args:
+///     pattern (str): The string pattern to match against
+///     strings (List[str]): List of strings to search through
+///     min (float, optional): Minimum similarity score (0.0 to 1.0). Defaults to 0.0
+///     max (float, optional): Maximum similarity score (0.0 to 1.0). Defaults to 1.0
+///     limit (int, optional): Maximum number of results to return. Defaults to 5
+///     q (int, optional): Length of each gram. Defaults to 2
+///     metric (str, optional): One of "jaccard", "dice", "cosine" or "overlap". Defaults to "jaccard"
+///
+/// Returns:
+///     List[Tuple[str, float]]: List of tuples containing (matched_string, similarity_score),
+///     sorted by score descending
+pub fn qgram_match(
+    pattern: &str,
+    strings: Vec<String>,
+    min: f64,
+    max: f64,
+    limit: usize,
+    q: usize,
+    metric: &str,
+) -> PyResult<Vec<(String, f64)>> {
+    _validate_args(q, metric)?;
+    Ok(_qgram_match(pattern, strings, min, max, limit, q, metric))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jaccard() {
+        // "night" / "nacht": bigrams {ni,ig,gh,ht} vs {na,ac,ch,ht}, share {ht}
+        assert_eq!(
+            _qgram_similarity("night", "nacht", 2, "jaccard"),
+            Some(1.0 / 7.0)
+        );
+        assert_eq!(_qgram_similarity("abc", "abc", 2, "jaccard"), Some(1.0));
+        assert_eq!(_qgram_similarity("abc", "xyz", 2, "jaccard"), Some(0.0));
+        assert_eq!(_qgram_similarity("", "", 2, "jaccard"), Some(1.0));
+        assert_eq!(_qgram_similarity("abc", "", 2, "jaccard"), Some(0.0));
+    }
+
+    #[test]
+    fn test_other_metrics() {
+        // Identical strings score 1.0 under every metric
+        for metric in ["jaccard", "dice", "cosine", "overlap"] {
+            assert_eq!(_qgram_similarity("night", "night", 2, metric), Some(1.0));
+        }
+        // Dice over the shared bigram of night/nacht: 2*1 / (4+4)
+        assert_eq!(_qgram_similarity("night", "nacht", 2, "dice"), Some(0.25));
+        // Overlap divides by the smaller distinct set
+        assert_eq!(_qgram_similarity("night", "nacht", 2, "overlap"), Some(0.25));
+    }
+
+    #[test]
+    fn test_short_string_fallback() {
+        // Shorter than q: the whole string becomes a single gram
+        assert_eq!(_qgram_similarity("a", "a", 2, "jaccard"), Some(1.0));
+        assert_eq!(_qgram_similarity("a", "b", 2, "jaccard"), Some(0.0));
+    }
+
+    #[test]
+    fn test_unknown_metric() {
+        assert_eq!(_qgram_similarity("abc", "abc", 2, "bogus"), None);
+    }
+}